@@ -1,11 +1,11 @@
 //! Request parameters types of Telegram bot methods.
 use serde::de::DeserializeOwned;
-use serde::Serialize;
+use serde::{Serialize, Serializer};
 use std::default::Default;
 use std::error::Error;
 use std::fmt;
 use super::types;
-use super::types::{ChatId, ForceReply, InlineKeyboardMarkup, MessageId,
+use super::types::{ChatId, ChatPermissions, ForceReply, InlineKeyboardMarkup, MessageId,
                    ParseMode, ReplyKeyboardMarkup, ReplyKeyboardRemove, UpdateId, UserId};
 
 
@@ -34,10 +34,6 @@ impl GetUpdates {
     pub fn new() -> GetUpdates {
         Default::default()
     }
-
-    pub fn offset(&mut self, x: UpdateId) {
-        self.offset = Some(x)
-    }
 }
 
 
@@ -45,6 +41,80 @@ impl GetUpdates {
 pub struct ApiError {
     error_code: i32,
     description: String,
+    parameters: Option<ResponseParameters>,
+}
+
+impl ApiError {
+    /// Number of seconds to wait before repeating a request that was rejected with a flood
+    /// limit error (HTTP 429), if Telegram told us.
+    pub fn retry_after(&self) -> Option<i32> {
+        self.parameters.as_ref().and_then(|p| p.retry_after)
+    }
+
+    /// The chat this error's group was migrated to, if Telegram reported one (the group was
+    /// upgraded to a supergroup).
+    pub fn migrate_to_chat_id(&self) -> Option<ChatId> {
+        self.parameters.as_ref().and_then(|p| p.migrate_to_chat_id.clone())
+    }
+
+    /// Classifies [`description`](ApiError::description) into a known kind, so callers can match
+    /// on semantics instead of matching the raw string themselves.
+    pub fn kind(&self) -> ApiErrorKind {
+        ApiErrorKind::from(self.description.as_str())
+    }
+}
+
+/// Common Telegram API error conditions, classified from the free-text `description` of a
+/// failed request. Falls back to [`Unknown`](ApiErrorKind::Unknown) for anything not
+/// recognized, so no information is lost.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ApiErrorKind {
+    /// `Forbidden: bot was blocked by the user`
+    BotBlockedByUser,
+    /// `Bad Request: chat not found`
+    ChatNotFound,
+    /// `Bad Request: user not found`
+    UserNotFound,
+    /// `Bad Request: message is not modified`
+    MessageNotModified,
+    /// `Bad Request: message to delete not found`
+    MessageToDeleteNotFound,
+    /// `Bad Request: message can't be edited`
+    MessageCantBeEdited,
+    /// HTTP 429, too many requests in a short time.
+    TooManyRequests,
+    /// `Forbidden: bot was kicked from the group chat`
+    BotKicked,
+    /// `Bad Request: not enough rights`
+    NotEnoughRights,
+    /// A description that doesn't match any of the above; the raw text is kept.
+    Unknown(String),
+}
+
+impl From<&str> for ApiErrorKind {
+    fn from(description: &str) -> ApiErrorKind {
+        if description.contains("bot was blocked by the user") {
+            ApiErrorKind::BotBlockedByUser
+        } else if description.contains("chat not found") {
+            ApiErrorKind::ChatNotFound
+        } else if description.contains("user not found") {
+            ApiErrorKind::UserNotFound
+        } else if description.contains("message is not modified") {
+            ApiErrorKind::MessageNotModified
+        } else if description.contains("message to delete not found") {
+            ApiErrorKind::MessageToDeleteNotFound
+        } else if description.contains("message can't be edited") {
+            ApiErrorKind::MessageCantBeEdited
+        } else if description.contains("Too Many Requests") {
+            ApiErrorKind::TooManyRequests
+        } else if description.contains("bot was kicked") {
+            ApiErrorKind::BotKicked
+        } else if description.contains("not enough rights") {
+            ApiErrorKind::NotEnoughRights
+        } else {
+            ApiErrorKind::Unknown(description.to_owned())
+        }
+    }
 }
 
 impl fmt::Display for ApiError {
@@ -61,6 +131,17 @@ impl Error for ApiError {
 }
 
 
+/// Extra information Telegram attaches to some failed requests.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ResponseParameters {
+    /// Seconds left to wait before the request can be repeated, present on flood-limit
+    /// (HTTP 429) errors.
+    pub retry_after: Option<i32>,
+    /// The group has been migrated to a supergroup with this identifier.
+    pub migrate_to_chat_id: Option<ChatId>,
+}
+
+
 
 /// Use this method to specify a url and receive incoming updates via an outgoing webhook.
 /// Whenever there is an update for the bot, we will send an HTTPS POST request to the specified
@@ -140,6 +221,285 @@ pub struct ForwardMessage {
     pub message_id: MessageId,
 }
 
+/// A file to be sent to Telegram: either a reference to a file already known to Telegram
+/// (`file_id`), a URL Telegram should fetch, or raw bytes to upload as part of a
+/// `multipart/form-data` request.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum InputFile {
+    FileId(String),
+    Url(String),
+    Upload { file_name: String, bytes: Vec<u8> },
+}
+
+impl InputFile {
+    /// Whether this file carries bytes that must be attached to a `multipart/form-data` body,
+    /// as opposed to a `file_id`/URL that can be sent as a plain string.
+    fn is_upload(&self) -> bool {
+        matches!(self, InputFile::Upload { .. })
+    }
+}
+
+impl Serialize for InputFile {
+    /// `FileId`/`Url` serialize to the string Telegram expects in that field; `Upload` serializes
+    /// to an `attach://` reference, with the actual bytes carried alongside in the
+    /// [`MultipartForm`] built by [`Method::into_multipart`].
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            InputFile::FileId(id) => serializer.serialize_str(id),
+            InputFile::Url(url) => serializer.serialize_str(url),
+            InputFile::Upload { file_name, .. } => {
+                serializer.serialize_str(&format!("attach://{}", file_name))
+            }
+        }
+    }
+}
+
+/// A single field of a `multipart/form-data` request body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MultipartField {
+    /// A plain text value, e.g. a `chat_id` or a JSON-encoded `reply_markup`.
+    Text(String),
+    /// An attached file's raw bytes, named so Telegram can match an `attach://` reference to it.
+    File { file_name: String, bytes: Vec<u8> },
+}
+
+/// A `multipart/form-data` request body, built by [`Method::into_multipart`] for methods that
+/// may carry an attached file upload. Field names are owned, since an uploaded file referenced
+/// via `attach://<file_name>` (see [`InputFile::serialize`]) needs a part named after that
+/// caller-chosen `file_name` rather than a fixed parameter name.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MultipartForm {
+    pub fields: Vec<(String, MultipartField)>,
+}
+
+impl MultipartForm {
+    fn new() -> MultipartForm {
+        Default::default()
+    }
+
+    fn push_text(&mut self, name: impl Into<String>, value: String) {
+        self.fields.push((name.into(), MultipartField::Text(value)));
+    }
+
+    fn push_file(&mut self, name: impl Into<String>, file: InputFile) {
+        match file {
+            InputFile::Upload { file_name, bytes } => {
+                self.fields.push((name.into(), MultipartField::File { file_name, bytes }));
+            }
+            other => self.push_text(name, text_field(&other)),
+        }
+    }
+}
+
+/// Renders a `Serialize` value the way a multipart text field wants it: bare strings
+/// unquoted, everything else JSON-encoded. Reuses `serde_json`, already a dependency of this
+/// crate for decoding Telegram's JSON responses.
+fn text_field<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value).unwrap_or(serde_json::Value::Null) {
+        serde_json::Value::String(s) => s,
+        other => other.to_string(),
+    }
+}
+
+/// Send a photo. On success, the sent [`Message`](types::Message) is returned.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SendPhoto {
+    pub chat_id: ChatTarget,
+    pub photo: InputFile,
+    pub caption: Option<String>,
+    pub parse_mode: Option<ParseMode>,
+    pub disable_notification: Option<bool>,
+    pub reply_to_message_id: Option<MessageId>,
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl SendPhoto {
+    pub fn new(chat_id: ChatTarget, photo: InputFile) -> SendPhoto {
+        SendPhoto {
+            chat_id,
+            photo,
+            caption: None,
+            parse_mode: None,
+            disable_notification: None,
+            reply_to_message_id: None,
+            reply_markup: None,
+        }
+    }
+}
+
+/// Send a general file. On success, the sent [`Message`](types::Message) is returned. Bots can
+/// currently send files of any type up to 50 MB in size.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SendDocument {
+    pub chat_id: ChatTarget,
+    pub document: InputFile,
+    pub caption: Option<String>,
+    pub parse_mode: Option<ParseMode>,
+    pub disable_notification: Option<bool>,
+    pub reply_to_message_id: Option<MessageId>,
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl SendDocument {
+    pub fn new(chat_id: ChatTarget, document: InputFile) -> SendDocument {
+        SendDocument {
+            chat_id,
+            document,
+            caption: None,
+            parse_mode: None,
+            disable_notification: None,
+            reply_to_message_id: None,
+            reply_markup: None,
+        }
+    }
+}
+
+/// Send an audio file for display in the Telegram music player. On success, the sent
+/// [`Message`](types::Message) is returned.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SendAudio {
+    pub chat_id: ChatTarget,
+    pub audio: InputFile,
+    pub caption: Option<String>,
+    pub parse_mode: Option<ParseMode>,
+    pub duration: Option<i32>,
+    pub performer: Option<String>,
+    pub title: Option<String>,
+    pub disable_notification: Option<bool>,
+    pub reply_to_message_id: Option<MessageId>,
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl SendAudio {
+    pub fn new(chat_id: ChatTarget, audio: InputFile) -> SendAudio {
+        SendAudio {
+            chat_id,
+            audio,
+            caption: None,
+            parse_mode: None,
+            duration: None,
+            performer: None,
+            title: None,
+            disable_notification: None,
+            reply_to_message_id: None,
+            reply_markup: None,
+        }
+    }
+}
+
+/// Send a video file. On success, the sent [`Message`](types::Message) is returned.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SendVideo {
+    pub chat_id: ChatTarget,
+    pub video: InputFile,
+    pub duration: Option<i32>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub caption: Option<String>,
+    pub parse_mode: Option<ParseMode>,
+    pub supports_streaming: Option<bool>,
+    pub disable_notification: Option<bool>,
+    pub reply_to_message_id: Option<MessageId>,
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl SendVideo {
+    pub fn new(chat_id: ChatTarget, video: InputFile) -> SendVideo {
+        SendVideo {
+            chat_id,
+            video,
+            duration: None,
+            width: None,
+            height: None,
+            caption: None,
+            parse_mode: None,
+            supports_streaming: None,
+            disable_notification: None,
+            reply_to_message_id: None,
+            reply_markup: None,
+        }
+    }
+}
+
+/// Send an audio file to be displayed as a playable voice message. On success, the sent
+/// [`Message`](types::Message) is returned.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SendVoice {
+    pub chat_id: ChatTarget,
+    pub voice: InputFile,
+    pub caption: Option<String>,
+    pub parse_mode: Option<ParseMode>,
+    pub duration: Option<i32>,
+    pub disable_notification: Option<bool>,
+    pub reply_to_message_id: Option<MessageId>,
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+impl SendVoice {
+    pub fn new(chat_id: ChatTarget, voice: InputFile) -> SendVoice {
+        SendVoice {
+            chat_id,
+            voice,
+            caption: None,
+            parse_mode: None,
+            duration: None,
+            disable_notification: None,
+            reply_to_message_id: None,
+            reply_markup: None,
+        }
+    }
+}
+
+/// A single item of a [`SendMediaGroup`] album.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InputMedia {
+    Photo {
+        media: InputFile,
+        caption: Option<String>,
+        parse_mode: Option<ParseMode>,
+    },
+    Video {
+        media: InputFile,
+        caption: Option<String>,
+        parse_mode: Option<ParseMode>,
+        width: Option<i32>,
+        height: Option<i32>,
+        duration: Option<i32>,
+        supports_streaming: Option<bool>,
+    },
+}
+
+impl InputMedia {
+    fn media(&self) -> &InputFile {
+        match self {
+            InputMedia::Photo { media, .. } => media,
+            InputMedia::Video { media, .. } => media,
+        }
+    }
+}
+
+/// Send a group of photos and videos as an album. On success, an array of the sent
+/// [`Message`](types::Message)s is returned.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SendMediaGroup {
+    pub chat_id: ChatTarget,
+    pub media: Vec<InputMedia>,
+    pub disable_notification: Option<bool>,
+    pub reply_to_message_id: Option<MessageId>,
+}
+
+impl SendMediaGroup {
+    pub fn new(chat_id: ChatTarget, media: Vec<InputMedia>) -> SendMediaGroup {
+        SendMediaGroup {
+            chat_id,
+            media,
+            disable_notification: None,
+            reply_to_message_id: None,
+        }
+    }
+}
+
 /// To get a list of profile pictures for a user. Returns a [`UserProfilePhotos`](types::UserProfilePhotos) object.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct GetUserProfilePhotos {
@@ -182,6 +542,137 @@ pub struct GetChatMember {
 }
 
 
+/// Use this method to ban a user in a group, a supergroup or a channel. In the case of
+/// supergroups and channels, the user will not be able to return to the chat on their own using
+/// invite links, etc., unless unbanned first. Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BanChatMember {
+    pub chat_id: ChatTarget,
+    pub user_id: UserId,
+    pub until_date: Option<i64>,
+    pub revoke_messages: Option<bool>,
+}
+
+impl BanChatMember {
+    pub fn new(chat_id: ChatTarget, user_id: UserId) -> BanChatMember {
+        BanChatMember {
+            chat_id,
+            user_id,
+            until_date: None,
+            revoke_messages: None,
+        }
+    }
+}
+
+/// Use this method to unban a previously banned user in a supergroup or channel. Returns True
+/// on success.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnbanChatMember {
+    pub chat_id: ChatTarget,
+    pub user_id: UserId,
+}
+
+/// Use this method to restrict a user in a supergroup. The bot must be an administrator in the
+/// supergroup for this to work and must have the appropriate admin rights. Returns True on
+/// success.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RestrictChatMember {
+    pub chat_id: ChatTarget,
+    pub user_id: UserId,
+    pub permissions: ChatPermissions,
+    pub until_date: Option<i64>,
+}
+
+impl RestrictChatMember {
+    pub fn new(chat_id: ChatTarget, user_id: UserId, permissions: ChatPermissions) -> RestrictChatMember {
+        RestrictChatMember {
+            chat_id,
+            user_id,
+            permissions,
+            until_date: None,
+        }
+    }
+}
+
+/// Use this method to promote or demote a user in a supergroup or a channel. The bot must be an
+/// administrator in the chat for this to work and must have the appropriate admin rights.
+/// Pass `false` for all boolean parameters to demote a user. Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PromoteChatMember {
+    pub chat_id: ChatTarget,
+    pub user_id: UserId,
+    pub is_anonymous: Option<bool>,
+    pub can_manage_chat: Option<bool>,
+    pub can_change_info: Option<bool>,
+    pub can_post_messages: Option<bool>,
+    pub can_edit_messages: Option<bool>,
+    pub can_delete_messages: Option<bool>,
+    pub can_manage_voice_chats: Option<bool>,
+    pub can_invite_users: Option<bool>,
+    pub can_restrict_members: Option<bool>,
+    pub can_pin_messages: Option<bool>,
+    pub can_promote_members: Option<bool>,
+}
+
+impl PromoteChatMember {
+    pub fn new(chat_id: ChatTarget, user_id: UserId) -> PromoteChatMember {
+        PromoteChatMember {
+            chat_id,
+            user_id,
+            is_anonymous: None,
+            can_manage_chat: None,
+            can_change_info: None,
+            can_post_messages: None,
+            can_edit_messages: None,
+            can_delete_messages: None,
+            can_manage_voice_chats: None,
+            can_invite_users: None,
+            can_restrict_members: None,
+            can_pin_messages: None,
+            can_promote_members: None,
+        }
+    }
+}
+
+/// Use this method to set default chat permissions for all members. The bot must be an
+/// administrator in the group or a supergroup for this to work and must have the
+/// `can_restrict_members` admin rights. Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SetChatPermissions {
+    pub chat_id: ChatTarget,
+    pub permissions: ChatPermissions,
+}
+
+/// Use this method to pin a message in a group, a supergroup, or a channel. The bot must be an
+/// administrator in the chat for this to work and must have the `can_pin_messages` admin right
+/// in the supergroup or `can_edit_messages` admin right in the channel. Returns True on success.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PinChatMessage {
+    pub chat_id: ChatTarget,
+    pub message_id: MessageId,
+    pub disable_notification: Option<bool>,
+}
+
+impl PinChatMessage {
+    pub fn new(chat_id: ChatTarget, message_id: MessageId) -> PinChatMessage {
+        PinChatMessage {
+            chat_id,
+            message_id,
+            disable_notification: None,
+        }
+    }
+}
+
+/// Use this method to unpin a message in a group, a supergroup, or a channel. The bot must be
+/// an administrator in the chat for this to work and must have the `can_pin_messages` admin
+/// right in the supergroup or `can_edit_messages` admin right in the channel. Returns True on
+/// success.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnpinChatMessage {
+    pub chat_id: ChatTarget,
+}
+
+
 /// Use this method to edit text and game messages sent by the bot or via the bot (for inline bots).
 /// On success, if edited message is sent by the bot, the edited [`Message`](types::Message) is
 /// returned, otherwise True is returned.
@@ -247,6 +738,9 @@ pub struct DeleteWebhook;
 #[derive(Serialize, Deserialize, Debug)]
 pub struct GetWebhookInfo;
 
+/// Default Telegram Bot API base URL.
+pub const DEFAULT_API_BASE: &str = "https://api.telegram.org";
+
 /// Telegram methods.
 pub trait Method: Serialize {
     /// Method name in the Telegram Bot API url.
@@ -254,9 +748,27 @@ pub trait Method: Serialize {
     /// Method return type.
     type Item: DeserializeOwned;
 
-    /// Get method url.
+    /// Get method url against the default Telegram Bot API server.
     fn url(token: String) -> String {
-        format!("https://api.telegram.org/bot{}/{}", token, Self::NAME)
+        Self::url_with_base(DEFAULT_API_BASE, &token, false)
+    }
+
+    /// Get method url against a custom API base, e.g. a self-hosted
+    /// [local Bot API server](https://core.telegram.org/bots/api#using-a-local-bot-api-server).
+    /// Set `test_env` to build a url under the `/test/` path segment, for Telegram's
+    /// [test environment](https://core.telegram.org/bots/webapps#testing-web-apps).
+    fn url_with_base(base: &str, token: &str, test_env: bool) -> String {
+        if test_env {
+            format!("{}/bot{}/test/{}", base, token, Self::NAME)
+        } else {
+            format!("{}/bot{}/{}", base, token, Self::NAME)
+        }
+    }
+
+    /// Multipart form body for methods that may carry an attached file upload. Returns `None`
+    /// for methods that should be sent as a plain JSON body.
+    fn into_multipart(self) -> Option<MultipartForm> where Self: Sized {
+        None
     }
 }
 
@@ -271,6 +783,48 @@ macro_rules! impl_method {
     ($Type: ty, $name: expr) => { impl_method!($Type, $name, bool); };
 }
 
+/// Like [`impl_method!`], but for a `SendXxx` method whose only attachment is the file held in
+/// `$file_field`: builds the `multipart/form-data` body out of `chat_id`, that file, and every
+/// `$extra` optional field, carrying each across as a text field (the file becomes the upload).
+macro_rules! impl_media_method {
+    ($Type: ty, $name: expr, $file_field: ident, [$($extra: ident),*]) => {
+        impl Method for $Type {
+            const NAME: &'static str = $name;
+            type Item = types::Message;
+
+            fn into_multipart(self) -> Option<MultipartForm> {
+                if !self.$file_field.is_upload() {
+                    return None;
+                }
+                let mut form = MultipartForm::new();
+                form.push_text("chat_id", text_field(&self.chat_id));
+                form.push_file(stringify!($file_field), self.$file_field);
+                $(
+                    if let Some(value) = self.$extra {
+                        form.push_text(stringify!($extra), text_field(&value));
+                    }
+                )*
+                Some(form)
+            }
+        }
+    };
+}
+
+/// Generates consuming builder methods for a request struct's optional fields, so callers can
+/// chain `Thing::new(...).field(value).other_field(value)` instead of using struct-update syntax.
+macro_rules! builder_methods {
+    ($Type: ty { $($field: ident: $FieldInner: ty),* $(,)? }) => {
+        impl $Type {
+            $(
+                pub fn $field(mut self, value: impl Into<$FieldInner>) -> Self {
+                    self.$field = Some(value.into());
+                    self
+                }
+            )*
+        }
+    };
+}
+
 
 impl_method!(GetUpdates, "getUpdates", Vec<types::Update>);
 impl_method!(GetMe, "getMe", types::User);
@@ -283,6 +837,177 @@ impl_method!(ForwardMessage, "forwardMessage", types::Message);
 impl_method!(EditMessageText, "editMessageText", types::Message);
 impl_method!(DeleteMessage, "deleteMessage");
 impl_method!(EditMessageCaption, "editMessageCaption");
+impl_method!(BanChatMember, "banChatMember");
+impl_method!(UnbanChatMember, "unbanChatMember");
+impl_method!(RestrictChatMember, "restrictChatMember");
+impl_method!(PromoteChatMember, "promoteChatMember");
+impl_method!(SetChatPermissions, "setChatPermissions");
+impl_method!(PinChatMessage, "pinChatMessage");
+impl_method!(UnpinChatMessage, "unpinChatMessage");
+
+impl_media_method!(SendPhoto, "sendPhoto", photo,
+                    [caption, parse_mode, disable_notification, reply_to_message_id, reply_markup]);
+impl_media_method!(SendDocument, "sendDocument", document,
+                    [caption, parse_mode, disable_notification, reply_to_message_id, reply_markup]);
+impl_media_method!(SendAudio, "sendAudio", audio,
+                    [caption, parse_mode, duration, performer, title, disable_notification,
+                     reply_to_message_id, reply_markup]);
+impl_media_method!(SendVideo, "sendVideo", video,
+                    [duration, width, height, caption, parse_mode, supports_streaming,
+                     disable_notification, reply_to_message_id, reply_markup]);
+impl_media_method!(SendVoice, "sendVoice", voice,
+                    [caption, parse_mode, duration, disable_notification, reply_to_message_id,
+                     reply_markup]);
+
+impl Method for SendMediaGroup {
+    const NAME: &'static str = "sendMediaGroup";
+    type Item = Vec<types::Message>;
+
+    fn into_multipart(self) -> Option<MultipartForm> {
+        if !self.media.iter().any(|item| item.media().is_upload()) {
+            return None;
+        }
+        let mut form = MultipartForm::new();
+        form.push_text("chat_id", text_field(&self.chat_id));
+        for item in &self.media {
+            if let InputFile::Upload { file_name, bytes } = item.media() {
+                form.fields.push((
+                    file_name.clone(),
+                    MultipartField::File { file_name: file_name.clone(), bytes: bytes.clone() },
+                ));
+            }
+        }
+        form.push_text("media", text_field(&self.media));
+        if let Some(disable_notification) = self.disable_notification {
+            form.push_text("disable_notification", text_field(&disable_notification));
+        }
+        if let Some(reply_to_message_id) = self.reply_to_message_id {
+            form.push_text("reply_to_message_id", text_field(&reply_to_message_id));
+        }
+        Some(form)
+    }
+}
+
+
+builder_methods!(GetUpdates {
+    offset: UpdateId,
+    limit: i32,
+    timeout: i32,
+    allowed_updates: Vec<String>,
+});
+builder_methods!(SetWebhook {
+    max_connections: i32,
+    allowed_updates: Vec<String>,
+});
+builder_methods!(SendMessage {
+    parse_mode: ParseMode,
+    disable_web_page_preview: bool,
+    disable_notification: bool,
+    reply_to_message_id: MessageId,
+    reply_markup: ReplyMarkup,
+});
+builder_methods!(EditMessageText {
+    chat_id: ChatTarget,
+    message_id: MessageId,
+    inline_message_id: String,
+    parse_mode: ParseMode,
+    disable_web_page_preview: bool,
+    reply_markup: InlineKeyboardMarkup,
+});
+builder_methods!(EditMessageCaption {
+    chat_id: ChatTarget,
+    message_id: MessageId,
+    inline_message_id: String,
+    caption: String,
+    parse_mode: ParseMode,
+    reply_markup: InlineKeyboardMarkup,
+});
+builder_methods!(EditMessageReplyMarkup {
+    chat_id: ChatTarget,
+    message_id: MessageId,
+    inline_message_id: String,
+    reply_markup: InlineKeyboardMarkup,
+});
+builder_methods!(SendPhoto {
+    caption: String,
+    parse_mode: ParseMode,
+    disable_notification: bool,
+    reply_to_message_id: MessageId,
+    reply_markup: ReplyMarkup,
+});
+builder_methods!(SendDocument {
+    caption: String,
+    parse_mode: ParseMode,
+    disable_notification: bool,
+    reply_to_message_id: MessageId,
+    reply_markup: ReplyMarkup,
+});
+builder_methods!(SendAudio {
+    caption: String,
+    parse_mode: ParseMode,
+    duration: i32,
+    performer: String,
+    title: String,
+    disable_notification: bool,
+    reply_to_message_id: MessageId,
+    reply_markup: ReplyMarkup,
+});
+builder_methods!(SendVideo {
+    duration: i32,
+    width: i32,
+    height: i32,
+    caption: String,
+    parse_mode: ParseMode,
+    supports_streaming: bool,
+    disable_notification: bool,
+    reply_to_message_id: MessageId,
+    reply_markup: ReplyMarkup,
+});
+builder_methods!(SendVoice {
+    caption: String,
+    parse_mode: ParseMode,
+    duration: i32,
+    disable_notification: bool,
+    reply_to_message_id: MessageId,
+    reply_markup: ReplyMarkup,
+});
+builder_methods!(SendMediaGroup {
+    disable_notification: bool,
+    reply_to_message_id: MessageId,
+});
+builder_methods!(BanChatMember {
+    until_date: i64,
+    revoke_messages: bool,
+});
+builder_methods!(RestrictChatMember {
+    until_date: i64,
+});
+builder_methods!(PromoteChatMember {
+    is_anonymous: bool,
+    can_manage_chat: bool,
+    can_change_info: bool,
+    can_post_messages: bool,
+    can_edit_messages: bool,
+    can_delete_messages: bool,
+    can_manage_voice_chats: bool,
+    can_invite_users: bool,
+    can_restrict_members: bool,
+    can_pin_messages: bool,
+    can_promote_members: bool,
+});
+builder_methods!(PinChatMessage {
+    disable_notification: bool,
+});
+builder_methods!(ChatPermissions {
+    can_send_messages: bool,
+    can_send_media_messages: bool,
+    can_send_polls: bool,
+    can_send_other_messages: bool,
+    can_add_web_page_previews: bool,
+    can_change_info: bool,
+    can_invite_users: bool,
+    can_pin_messages: bool,
+});
 
 
 #[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -292,6 +1017,21 @@ pub struct TelegramResult<T> // WTF! JUST WORK!
     pub description: Option<String>,
     pub err_code: Option<i32>,
     pub result: Option<T>,
+    pub parameters: Option<ResponseParameters>,
+}
+
+impl<T> TelegramResult<T> {
+    /// Splits this response into Telegram's usual success/error shape.
+    pub fn into_result(self) -> Result<T, ApiError> {
+        match self.result {
+            Some(result) if self.ok => Ok(result),
+            _ => Err(ApiError {
+                error_code: self.err_code.unwrap_or_default(),
+                description: self.description.unwrap_or_default(),
+                parameters: self.parameters,
+            }),
+        }
+    }
 }
 
 pub type UpdateList = TelegramResult<Vec<types::Update>>;